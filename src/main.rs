@@ -1,75 +1,204 @@
-use jwalk::WalkDir;
-use rayon::ThreadPoolBuilder;
+use clap::Parser;
+use crossbeam_channel::{Receiver, RecvTimeoutError};
+use ignore::overrides::OverrideBuilder;
+use ignore::{WalkBuilder, WalkState};
+use jobserver::Client;
 use rayon::prelude::*;
 use std::collections::BTreeSet;
 use std::env;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, ExitStatus};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long the receiver waits for the walk to finish quickly before it starts
+/// printing workspaces as they are found instead of all at once, sorted.
+const DISCOVERY_BUFFER_WINDOW: Duration = Duration::from_millis(300);
+/// Upper bound on how many workspaces we buffer before switching to streaming,
+/// so a single giant tree can't hold back output indefinitely.
+const DISCOVERY_BUFFER_LIMIT: usize = 200;
+
+/// Find every Scarb workspace under a root directory and run `scarb clean` in each of them.
+#[derive(Parser)]
+#[command(author, version, about)]
+struct Cli {
+    /// Root directory to search for Scarb workspaces.
+    #[arg(default_value = ".")]
+    root: PathBuf,
+
+    /// Number of workspaces to clean in parallel (falls back to SCARB_CLEAN_JOBS, then to one job per workspace).
+    #[arg(short = 'j', long = "jobs")]
+    jobs: Option<usize>,
+
+    /// Skip the interactive confirmation prompt.
+    #[arg(short = 'y', long = "yes")]
+    yes: bool,
+
+    /// Maximum directory depth to descend into while searching for workspaces.
+    #[arg(long = "max-depth")]
+    max_depth: Option<usize>,
+
+    /// Only report workspaces whose path matches this glob. Skips subtrees the glob's literal
+    /// (non-wildcard) path prefix rules out, but still has to descend through any directory a
+    /// wildcard could match. May be repeated.
+    #[arg(long = "include")]
+    include: Vec<String>,
+
+    /// Skip paths matching this glob. May be repeated.
+    #[arg(long = "exclude")]
+    exclude: Vec<String>,
+
+    /// List discovered workspaces and the space they would reclaim, without cleaning them.
+    #[arg(long = "list")]
+    list: bool,
+}
 
 fn main() {
-    let start_dir = match env::current_dir() {
+    let cli = Cli::parse();
+
+    let start_dir = match cli.root.canonicalize() {
         Ok(dir) => dir,
         Err(err) => {
-            eprintln!("Failed to determine current directory: {err}");
+            eprintln!("Failed to resolve root path {}: {err}", cli.root.display());
+            std::process::exit(1);
+        }
+    };
+
+    let filters = DiscoveryFilters {
+        include: cli.include.clone(),
+        exclude: cli.exclude.clone(),
+    };
+
+    let receiver = match find_scarb_workspaces(&start_dir, &filters, cli.max_depth) {
+        Ok(receiver) => receiver,
+        Err(err) => {
+            eprintln!("Failed to build workspace discovery filters: {err}");
             std::process::exit(1);
         }
     };
 
-    let workspaces = find_scarb_workspaces(&start_dir);
+    let (workspaces, streamed) = collect_workspaces(&receiver, &start_dir);
 
     if workspaces.is_empty() {
         println!("No Scarb workspaces found under {}.", start_dir.display());
         return;
     }
 
+    let sizes: Vec<u64> = workspaces
+        .par_iter()
+        .map(|workspace| target_dir_size(&workspace.join("target")))
+        .collect();
+    let total_size: u64 = sizes.iter().sum();
+
     println!("Found {} Scarb workspace(s):", workspaces.len());
-    for workspace in &workspaces {
-        println!("- {}", display_path(workspace, &start_dir));
+    for (workspace, size) in workspaces.iter().zip(&sizes) {
+        // Already printed once while streaming (long scans print paths as they're found); skip
+        // it here instead of showing every one of them twice.
+        if streamed.contains(workspace) {
+            continue;
+        }
+        println!(
+            "- {} ({})",
+            display_path(workspace, &start_dir),
+            format_size(*size)
+        );
+    }
+    println!("Total reclaimable space: {}", format_size(total_size));
+
+    if cli.list {
+        return;
     }
 
-    if !ask_for_confirmation("\nRun `scarb clean` in all listed directories? [y/N]: ") {
+    let prompt = format!(
+        "\nRun `scarb clean` in all listed directories (reclaiming up to {})? [y/N]: ",
+        format_size(total_size)
+    );
+    if !cli.yes && !ask_for_confirmation(&prompt) {
         println!("Aborted.");
         return;
     }
 
-    let workspace_list: Vec<_> = workspaces.iter().cloned().collect();
-    let max_jobs = parse_jobs_from_env().unwrap_or_else(|| workspace_list.len().max(1));
-    let jobs = max_jobs.min(workspace_list.len().max(1));
+    let workspace_list: Vec<_> = workspaces.into_iter().zip(sizes).collect();
+    let fallback_jobs = validate_jobs(cli.jobs)
+        .or_else(parse_jobs_from_env)
+        .unwrap_or_else(|| workspace_list.len().max(1))
+        .min(workspace_list.len().max(1));
+    let (client, inherited) = jobserver_client(fallback_jobs);
 
-    println!("\nRunning `scarb clean` in parallel with up to {jobs} job(s)...");
+    if inherited {
+        println!("\nRunning `scarb clean` using the inherited jobserver...");
+    } else {
+        println!("\nRunning `scarb clean` with a local jobserver sized for {fallback_jobs} job(s)...");
+    }
 
-    let pool = match ThreadPoolBuilder::new().num_threads(jobs).build() {
-        Ok(pool) => pool,
-        Err(err) => {
-            eprintln!("Failed to create rayon thread pool: {err}");
-            std::process::exit(1);
-        }
-    };
+    // The worker pool just needs enough threads to keep every jobserver token busy; it is not
+    // itself the concurrency limit (the jobserver is). Sizing it off `fallback_jobs` would spawn
+    // one thread per workspace whenever `--jobs`/`SCARB_CLEAN_JOBS` are unset (its default is
+    // `workspace_list.len()`), even though an *inherited* jobserver's token budget is unrelated to
+    // that count — e.g. `make -j4` on a 2,000-workspace monorepo would spawn 2,000 threads that
+    // all immediately pile up on `client.acquire()` fighting over 4 tokens. Cap it at the CPU
+    // count instead: that's plenty to keep a handful of tokens saturated without the thread count
+    // scaling with the number of workspaces.
+    let worker_threads = thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .min(workspace_list.len().max(1));
+    let results: Mutex<Vec<(&PathBuf, u64, io::Result<ExitStatus>)>> =
+        Mutex::new(Vec::with_capacity(workspace_list.len()));
+    let next_index = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for _ in 0..worker_threads {
+            let client = &client;
+            let results = &results;
+            let next_index = &next_index;
+            let workspace_list = &workspace_list;
+            scope.spawn(move || {
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::Relaxed);
+                    let Some((workspace, size_before)) = workspace_list.get(index) else {
+                        break;
+                    };
 
-    let results = pool.install(|| {
-        workspace_list
-            .par_iter()
-            .map(|workspace| {
-                let manifest_path = workspace.join("Scarb.toml");
-                let status = Command::new("scarb")
-                    .arg("--manifest-path")
-                    .arg(&manifest_path)
-                    .arg("clean")
-                    .env_remove("SCARB_MANIFEST_PATH")
-                    .current_dir(workspace)
-                    .status();
-
-                (workspace, status)
-            })
-            .collect::<Vec<_>>()
+                    let token = client
+                        .acquire()
+                        .expect("failed to acquire a jobserver token");
+
+                    let manifest_path = workspace.join("Scarb.toml");
+                    let mut command = Command::new("scarb");
+                    command
+                        .arg("--manifest-path")
+                        .arg(&manifest_path)
+                        .arg("clean")
+                        .env_remove("SCARB_MANIFEST_PATH")
+                        .current_dir(workspace);
+                    client.configure(&mut command);
+
+                    let status = command.status();
+                    drop(token);
+
+                    results.lock().unwrap().push((workspace, *size_before, status));
+                }
+            });
+        }
     });
+    let results = results.into_inner().unwrap();
 
     let mut failures = 0usize;
-    for (workspace, status) in results {
+    let mut total_reclaimed = 0u64;
+    for (workspace, size_before, status) in results {
         match status {
             Ok(exit_status) if exit_status.success() => {
-                println!("- {}: Success.", workspace.display());
+                let size_after = target_dir_size(&workspace.join("target"));
+                let reclaimed = size_before.saturating_sub(size_after);
+                total_reclaimed += reclaimed;
+                println!(
+                    "- {}: Success. Reclaimed {}.",
+                    workspace.display(),
+                    format_size(reclaimed)
+                );
             }
             Ok(exit_status) => {
                 failures += 1;
@@ -86,60 +215,271 @@ fn main() {
     }
 
     if failures == 0 {
-        println!("\nDone. All workspaces cleaned successfully.");
+        println!(
+            "\nDone. All workspaces cleaned successfully. Reclaimed {} in total.",
+            format_size(total_reclaimed)
+        );
     } else {
-        eprintln!("\nDone with {failures} failure(s).");
+        eprintln!(
+            "\nDone with {failures} failure(s). Reclaimed {} in total.",
+            format_size(total_reclaimed)
+        );
         std::process::exit(1);
     }
 }
 
-fn find_scarb_workspaces(dir: &Path) -> BTreeSet<PathBuf> {
-    let mut workspaces = BTreeSet::new();
+fn target_dir_size(target_dir: &Path) -> u64 {
+    if !target_dir.exists() {
+        return 0;
+    }
+
+    let total = AtomicU64::new(0);
+
+    // `target/` ships its own `.gitignore` (`/*`), so a standard-filters walk would see it as
+    // entirely ignored and silently report 0 bytes. Disable gitignore/hidden/global filtering
+    // here; we want every byte actually on disk, not what a VCS would track.
+    WalkBuilder::new(target_dir)
+        .standard_filters(false)
+        .build_parallel()
+        .run(|| {
+            let total = &total;
+            Box::new(move |entry_result| {
+                if let Ok(entry) = entry_result {
+                    if entry.file_type().is_some_and(|file_type| file_type.is_file()) {
+                        if let Ok(metadata) = entry.metadata() {
+                            total.fetch_add(metadata.len(), Ordering::Relaxed);
+                        }
+                    }
+                }
+
+                WalkState::Continue
+            })
+        });
+
+    total.load(Ordering::Relaxed)
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    if unit_index == 0 {
+        format!("{bytes} {}", UNITS[unit_index])
+    } else {
+        format!("{size:.1} {}", UNITS[unit_index])
+    }
+}
+
+#[derive(Default)]
+struct DiscoveryFilters {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+/// Returns the leading path components of `pattern` that contain no glob metacharacters, e.g.
+/// `"apps/*/src"` -> `["apps"]`. A directory outside this prefix can never contain a match.
+///
+/// Gitignore-style patterns with no interior slash (e.g. `"my-workspace"`, same as what
+/// `OverrideBuilder` matches here) are unanchored: they match a basename at *any* depth, not just
+/// at the search root, so no prefix can be safely ruled out for them.
+fn literal_prefix(pattern: &str) -> Vec<String> {
+    if !pattern.trim_end_matches('/').contains('/') {
+        return Vec::new();
+    }
+
+    let mut prefix = Vec::new();
+    for component in pattern.split('/') {
+        if component.is_empty() || component.contains(['*', '?', '[']) {
+            break;
+        }
+        prefix.push(component.to_string());
+    }
+    prefix
+}
+
+/// Whether `relative` (a directory path relative to the search root) could still lead to a path
+/// matching one of `include_prefixes`. This only rules a directory out once it has diverged from
+/// every pattern's literal prefix; a pattern whose prefix starts with a wildcard (e.g. `"**/foo"`)
+/// never rules anything out, since any directory could still lead to a match.
+fn may_lead_to_include(relative: &Path, include_prefixes: &[Vec<String>]) -> bool {
+    if include_prefixes.is_empty() {
+        return true;
+    }
+
+    let components: Vec<&str> = relative
+        .components()
+        .filter_map(|component| component.as_os_str().to_str())
+        .collect();
+
+    include_prefixes.iter().any(|prefix| {
+        let shared = components.len().min(prefix.len());
+        components[..shared] == prefix[..shared]
+    })
+}
+
+fn find_scarb_workspaces(
+    dir: &Path,
+    filters: &DiscoveryFilters,
+    max_depth: Option<usize>,
+) -> Result<Receiver<PathBuf>, ignore::Error> {
+    // Excludes only ever narrow the walk (no non-negated pattern), so they're safe to
+    // apply at the traversal level without putting the walker into whitelist mode.
+    let mut exclude_overrides = OverrideBuilder::new(dir);
+    for pattern in &filters.exclude {
+        exclude_overrides.add(&format!("!{pattern}"))?;
+    }
+    let exclude_overrides = exclude_overrides.build()?;
+
+    // Includes are matched against discovered workspace roots below for the authoritative
+    // decision (a whitelist override, applied straight to the walk, prunes any intermediate
+    // directory that doesn't itself match the glob, which would stop us from ever reaching a
+    // nested match). Directory descent is separately pruned by `may_lead_to_include` below, using
+    // each pattern's literal (non-wildcard) path prefix: that's the only part of a glob we can
+    // rule a directory out against without risking skipping past a real match.
+    let mut include_overrides = OverrideBuilder::new(dir);
+    for pattern in &filters.include {
+        include_overrides.add(pattern)?;
+    }
+    let include_overrides = include_overrides.build()?;
+    let has_includes = !filters.include.is_empty();
+    let include_prefixes: Arc<Vec<Vec<String>>> = Arc::new(
+        filters
+            .include
+            .iter()
+            .map(|pattern| literal_prefix(pattern))
+            .collect(),
+    );
+
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    let dir = dir.to_path_buf();
+
+    thread::spawn(move || {
+        WalkBuilder::new(&dir)
+            .overrides(exclude_overrides)
+            .max_depth(max_depth)
+            .sort_by_file_name(|a, b| a.cmp(b))
+            .build_parallel()
+            .run(|| {
+                let sender = sender.clone();
+                let include_overrides = include_overrides.clone();
+                let include_prefixes = Arc::clone(&include_prefixes);
+                let dir = dir.clone();
+                Box::new(move |entry_result| {
+                    let entry = match entry_result {
+                        Ok(entry) => entry,
+                        Err(err) => {
+                            eprintln!("Skipping unreadable path: {err}");
+                            return WalkState::Continue;
+                        }
+                    };
+
+                    let path = entry.path();
+                    let is_dir = entry.file_type().is_some_and(|file_type| file_type.is_dir());
 
-    let walker =
-        WalkDir::new(dir).process_read_dir(|_depth, _parent_path, _read_dir_state, children| {
-            let has_scarb_toml = children.iter().any(|entry_result| {
-                entry_result.as_ref().ok().is_some_and(|entry| {
-                    entry
-                        .path()
-                        .file_name()
-                        .is_some_and(|name| name == "Scarb.toml")
-                        && entry.file_type().is_file()
+                    if is_dir && has_includes {
+                        let relative = path.strip_prefix(&dir).unwrap_or(path);
+                        if !may_lead_to_include(relative, &include_prefixes) {
+                            return WalkState::Skip;
+                        }
+                    }
+
+                    let is_scarb_toml = entry
+                        .file_type()
+                        .is_some_and(|file_type| file_type.is_file())
+                        && path.file_name().is_some_and(|name| name == "Scarb.toml");
+
+                    if is_scarb_toml {
+                        if let Some(parent) = path.parent() {
+                            let included = !has_includes
+                                || include_overrides.matched(parent, true).is_whitelist();
+                            if included {
+                                sender.send(parent.to_path_buf()).ok();
+                            }
+                        }
+                    }
+
+                    WalkState::Continue
                 })
             });
+    });
 
-            if has_scarb_toml {
-                children.retain(|entry_result| {
-                    entry_result
-                        .as_ref()
-                        .ok()
-                        .is_none_or(|entry| !entry.file_type().is_dir())
-                });
-            }
-        });
+    Ok(receiver)
+}
 
-    for entry_result in walker {
-        let entry = match entry_result {
-            Ok(entry) => entry,
-            Err(err) => {
-                eprintln!("Skipping unreadable path: {err}");
-                continue;
-            }
-        };
-
-        if entry.file_type().is_file()
-            && entry
-                .path()
-                .file_name()
-                .is_some_and(|name| name == "Scarb.toml")
-        {
-            if let Some(parent) = entry.path().parent() {
-                workspaces.insert(parent.to_path_buf());
+/// Drops any discovered workspace that is nested inside another discovered workspace.
+///
+/// The walk above no longer prunes live (that depended on a racy cross-thread check), so
+/// this runs once over the complete, sorted set of candidates instead: since `PathBuf`
+/// ordering compares path components, an ancestor always sorts before its descendants, so a
+/// single pass tracking the last kept root is enough to deterministically drop nested ones.
+fn prune_nested_workspaces(discovered: BTreeSet<PathBuf>) -> BTreeSet<PathBuf> {
+    let mut roots: BTreeSet<PathBuf> = BTreeSet::new();
+
+    for candidate in discovered {
+        let is_nested = roots
+            .iter()
+            .next_back()
+            .is_some_and(|root| candidate.starts_with(root));
+        if !is_nested {
+            roots.insert(candidate);
+        }
+    }
+
+    roots
+}
+
+/// Collects every discovered workspace, printing each one's path as soon as it's found once the
+/// walk is taking long enough that the buffered, sorted-summary approach would otherwise leave
+/// the user staring at nothing. Returns the deduplicated, pruned workspace set together with the
+/// subset of it that was already printed here, so the summary below doesn't print it twice.
+fn collect_workspaces(
+    receiver: &Receiver<PathBuf>,
+    start_dir: &Path,
+) -> (BTreeSet<PathBuf>, BTreeSet<PathBuf>) {
+    let mut buffer = Vec::new();
+    let mut streamed = BTreeSet::new();
+    let deadline = Instant::now() + DISCOVERY_BUFFER_WINDOW;
+    let mut streaming = false;
+
+    loop {
+        if streaming {
+            match receiver.recv() {
+                Ok(workspace) => {
+                    println!("Discovered: {}", display_path(&workspace, start_dir));
+                    streamed.insert(workspace.clone());
+                    buffer.push(workspace);
+                }
+                Err(_) => break,
             }
+            continue;
+        }
+
+        if buffer.len() >= DISCOVERY_BUFFER_LIMIT {
+            streaming = true;
+            continue;
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            streaming = true;
+            continue;
+        }
+
+        match receiver.recv_timeout(remaining) {
+            Ok(workspace) => buffer.push(workspace),
+            Err(RecvTimeoutError::Timeout) => streaming = true,
+            Err(RecvTimeoutError::Disconnected) => break,
         }
     }
 
-    workspaces
+    let workspaces = prune_nested_workspaces(buffer.into_iter().collect());
+    (workspaces, streamed)
 }
 
 fn ask_for_confirmation(prompt: &str) -> bool {
@@ -167,6 +507,32 @@ fn display_path(path: &Path, base: &Path) -> String {
     }
 }
 
+/// Returns a jobserver client together with whether it was inherited from a
+/// surrounding `make -jN` (or other jobserver-aware) build rather than created locally.
+fn jobserver_client(fallback_jobs: usize) -> (Client, bool) {
+    if let Some(client) = unsafe { Client::from_env() } {
+        return (client, true);
+    }
+
+    match Client::new(fallback_jobs) {
+        Ok(client) => (client, false),
+        Err(err) => {
+            eprintln!("Failed to create a jobserver with {fallback_jobs} slot(s): {err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn validate_jobs(jobs: Option<usize>) -> Option<usize> {
+    match jobs {
+        Some(0) => {
+            eprintln!("Ignoring --jobs 0, value must be >= 1.");
+            None
+        }
+        other => other,
+    }
+}
+
 fn parse_jobs_from_env() -> Option<usize> {
     let raw = match env::var("SCARB_CLEAN_JOBS") {
         Ok(raw) => raw,
@@ -185,3 +551,93 @@ fn parse_jobs_from_env() -> Option<usize> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_nested_workspaces_drops_descendants_of_a_kept_root() {
+        let discovered = BTreeSet::from([
+            PathBuf::from("/repo"),
+            PathBuf::from("/repo/crates/a"),
+            PathBuf::from("/repo/crates/b"),
+        ]);
+
+        let pruned = prune_nested_workspaces(discovered);
+
+        assert_eq!(pruned, BTreeSet::from([PathBuf::from("/repo")]));
+    }
+
+    #[test]
+    fn prune_nested_workspaces_keeps_unrelated_siblings() {
+        let discovered = BTreeSet::from([
+            PathBuf::from("/repo/apps/a"),
+            PathBuf::from("/repo/apps/b"),
+            PathBuf::from("/repo/libs/c"),
+        ]);
+
+        let pruned = prune_nested_workspaces(discovered.clone());
+
+        assert_eq!(pruned, discovered);
+    }
+
+    #[test]
+    fn prune_nested_workspaces_does_not_confuse_name_prefixes_for_nesting() {
+        // `/repo/app` is not an ancestor of `/repo/app-extra`: `starts_with` compares whole path
+        // components, not raw string prefixes, so both must be kept.
+        let discovered = BTreeSet::from([
+            PathBuf::from("/repo/app"),
+            PathBuf::from("/repo/app-extra"),
+        ]);
+
+        let pruned = prune_nested_workspaces(discovered.clone());
+
+        assert_eq!(pruned, discovered);
+    }
+
+    #[test]
+    fn format_size_picks_the_largest_unit_under_a_thousand_and_rounds_to_one_decimal() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1024), "1.0 KiB");
+        assert_eq!(format_size(1024 * 1024), "1.0 MiB");
+        assert_eq!(format_size(1536 * 1024), "1.5 MiB");
+        assert_eq!(format_size(1024u64.pow(4)), "1.0 TiB");
+        // TiB is the largest unit, so sizes beyond it are still reported in TiB rather than
+        // overflowing into a made-up unit.
+        assert_eq!(format_size(u64::MAX), "16777216.0 TiB");
+    }
+
+    #[test]
+    fn validate_jobs_rejects_zero_but_passes_through_everything_else() {
+        assert_eq!(validate_jobs(Some(0)), None);
+        assert_eq!(validate_jobs(Some(4)), Some(4));
+        assert_eq!(validate_jobs(None), None);
+    }
+
+    #[test]
+    fn literal_prefix_stops_at_the_first_wildcard_component() {
+        assert_eq!(literal_prefix("apps/foo/src"), vec!["apps", "foo", "src"]);
+        assert_eq!(literal_prefix("apps/*/src"), vec!["apps"]);
+        assert_eq!(literal_prefix("**/vendor"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn literal_prefix_is_empty_for_an_unanchored_single_component_pattern() {
+        // No interior slash means this is a gitignore-style basename match that can occur at any
+        // depth, not just at the search root, so nothing can be pruned for it.
+        assert_eq!(literal_prefix("my-workspace"), Vec::<String>::new());
+        assert_eq!(literal_prefix("*.toml"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn may_lead_to_include_allows_ancestors_and_descendants_of_a_literal_prefix() {
+        let prefixes = vec![literal_prefix("apps/foo/src")];
+
+        assert!(may_lead_to_include(Path::new("apps"), &prefixes));
+        assert!(may_lead_to_include(Path::new("apps/foo"), &prefixes));
+        assert!(may_lead_to_include(Path::new("apps/foo/src/deep"), &prefixes));
+        assert!(!may_lead_to_include(Path::new("libs"), &prefixes));
+    }
+}